@@ -0,0 +1,124 @@
+#![allow(unused_imports)]
+#![cfg_attr(target_arch = "wasm32", no_std)]
+
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+use xrpl_wasm_stdlib::wasm_export;
+use xrpl_wasm_stdlib::core::params::function::get_function_param;
+use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+use xrpl_wasm_stdlib::core::types::amount::Amount;
+use xrpl_wasm_stdlib::host::trace::{trace, trace_num, DataRepr};
+
+const SUCCESS: i32 = 0;
+const BAD_PARAM: i32 = -1;
+const TOO_MANY_RECIPIENTS: i32 = -2;
+const ZERO_AMOUNT: i32 = -3;
+const INSUFFICIENT_BALANCE: i32 = -4;
+const COMMIT_ERROR_BASE: i32 = -1000;
+
+// Upper bound on recipients per call so the pre-check loop can't be used to
+// stall the host with an unbounded scan.
+const MAX_RECIPIENTS: u32 = 32;
+
+fn exit(message: &str, error_code: i32) -> i32 {
+    let _ = trace(message);
+    let _ = trace_num("Error Code:", error_code as i64);
+    error_code
+}
+
+// Reads the `(AccountID, Amount)` pair for recipient `index`, where pairs are
+// packed back-to-back starting at function param 1 (param 0 is `n`).
+fn read_pair(index: u32) -> Result<(AccountID, Amount), i32> {
+    let account_param = 1 + index * 2;
+    let amount_param = 2 + index * 2;
+
+    let account = match get_function_param::<AccountID>(account_param as usize) {
+        Ok(a) => a,
+        Err(err) => {
+            let _ = trace_num("`recipient` Parameter Error Code:", err as i64);
+            return Err(BAD_PARAM);
+        }
+    };
+
+    let amount = match get_function_param::<Amount>(amount_param as usize) {
+        Ok(a) => a,
+        Err(err) => {
+            let _ = trace_num("`amount` Parameter Error Code:", err as i64);
+            return Err(BAD_PARAM);
+        }
+    };
+
+    Ok((account, amount))
+}
+
+#[wasm_export(
+    exit = exit,
+    instance(initialBalance: Amount)
+)]
+pub extern "C" fn batch_transfer() -> i32 {
+    let n = match get_function_param::<u32>(0) {
+        Ok(n) => n,
+        Err(err) => {
+            let _ = trace_num("`n` Parameter Error Code:", err as i64);
+            return exit("Batch transfer failed", BAD_PARAM);
+        }
+    };
+
+    if n > MAX_RECIPIENTS {
+        let _ = trace_num("Recipients requested:", n as i64);
+        return exit("Batch transfer failed", TOO_MANY_RECIPIENTS);
+    }
+
+    // Phase 1: validate every pair and pre-check the total against the
+    // instance's available balance before any transfer is attempted.
+    //
+    // `Amount` only ever represents native XRP drops in this tree (there is
+    // no TokenAmount/MPT pair support here), so there is a single asset and
+    // a single running total rather than a per-asset breakdown.
+    let mut total: i64 = 0;
+    let mut i = 0;
+    while i < n {
+        let (_account, amount) = match read_pair(i) {
+            Ok(pair) => pair,
+            Err(code) => return exit("Batch transfer failed", code),
+        };
+
+        let value = amount.to_i64();
+        if value == 0 {
+            let _ = trace_num("Zero amount at index:", i as i64);
+            return exit("Batch transfer failed", ZERO_AMOUNT);
+        }
+
+        total = total.saturating_add(value);
+        i += 1;
+    }
+
+    if total > initialBalance.to_i64() {
+        let _ = trace_num("Requested total:", total);
+        return exit("Batch transfer failed", INSUFFICIENT_BALANCE);
+    }
+
+    // Phase 2: the pre-check passed, so every recipient in the batch is
+    // expected to succeed. Commit one at a time, tracing progress so the
+    // caller can reconcile against the partially-completed batch if the
+    // host still rejects a transfer.
+    let mut succeeded = 0;
+    while succeeded < n {
+        let (account, amount) = match read_pair(succeeded) {
+            Ok(pair) => pair,
+            Err(code) => return exit("Batch transfer failed", code),
+        };
+
+        let tx_id = amount.transfer(&account);
+        if tx_id < 0 {
+            let _ = trace_num("Recipients succeeded before failure:", succeeded as i64);
+            let _ = trace_num("AMOUNT Transfer Error Code:", tx_id as i64);
+            return exit("Batch transfer failed", COMMIT_ERROR_BASE - succeeded as i32);
+        }
+
+        succeeded += 1;
+    }
+
+    return exit("Batch transfer successful", SUCCESS);
+}
@@ -0,0 +1,113 @@
+#![allow(unused_imports)]
+#![cfg_attr(target_arch = "wasm32", no_std)]
+
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+use xrpl_wasm_stdlib::wasm_export;
+use xrpl_wasm_stdlib::core::params::function::get_function_param;
+use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+use xrpl_wasm_stdlib::core::types::amount::Amount;
+use xrpl_wasm_stdlib::host::trace::{trace, trace_data, trace_num, DataRepr};
+
+const SUCCESS: i32 = 0;
+const BAD_PARAM: i32 = -1;
+const UNKNOWN_NETWORK: i32 = -2;
+const BAD_FOREIGN_ADDRESS_LEN: i32 = -3;
+const MEMO_TOO_LARGE: i32 = -4;
+
+// Expected raw address width for the foreign networks this bridge knows how
+// to route to. Unlisted network ids are rejected rather than guessed at.
+fn foreign_address_len(network_id: u32) -> Option<usize> {
+    match network_id {
+        // Ethereum and other 20-byte-address EVM chains.
+        1 => Some(20),
+        // Chains with 32-byte addresses (e.g. Solana, Cosmos SDK chains).
+        2 => Some(32),
+        _ => None,
+    }
+}
+
+const MAX_MEMO_LEN: usize = 128;
+
+fn exit(message: &str, error_code: i32) -> i32 {
+    let _ = trace(message);
+    let _ = trace_num("Error Code:", error_code as i64);
+    error_code
+}
+
+#[wasm_export(
+    exit = exit,
+    instance(bridgeAccount: AccountID)
+)]
+pub extern "C" fn bridge_transfer(amount: Amount) -> i32 {
+    let network_id = match get_function_param::<u32>(1) {
+        Ok(id) => id,
+        Err(err) => {
+            let _ = trace_num("`network_id` Parameter Error Code:", err as i64);
+            return exit("Bridge transfer failed", BAD_PARAM);
+        }
+    };
+
+    let expected_len = match foreign_address_len(network_id) {
+        Some(len) => len,
+        None => {
+            let _ = trace_num("Unknown network id:", network_id as i64);
+            return exit("Bridge transfer failed", UNKNOWN_NETWORK);
+        }
+    };
+
+    let foreign_account = match get_function_param::<&[u8]>(2) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = trace_num("`foreign_account` Parameter Error Code:", err as i64);
+            return exit("Bridge transfer failed", BAD_PARAM);
+        }
+    };
+
+    if foreign_account.len() != expected_len {
+        let _ = trace_num("Foreign address length:", foreign_account.len() as i64);
+        return exit("Bridge transfer failed", BAD_FOREIGN_ADDRESS_LEN);
+    }
+
+    // Encode {network_id, foreign_account, amount} as a length-prefixed memo
+    // for the off-chain relayer watching this bridge account.
+    let mut memo = [0u8; MAX_MEMO_LEN];
+    let mut offset = 0;
+
+    let network_id_bytes = network_id.to_be_bytes();
+    memo[offset..offset + network_id_bytes.len()].copy_from_slice(&network_id_bytes);
+    offset += network_id_bytes.len();
+
+    if offset + 1 + foreign_account.len() > MAX_MEMO_LEN {
+        return exit("Bridge transfer failed", MEMO_TOO_LARGE);
+    }
+    memo[offset] = foreign_account.len() as u8;
+    offset += 1;
+    memo[offset..offset + foreign_account.len()].copy_from_slice(foreign_account);
+    offset += foreign_account.len();
+
+    let amount_drops = amount.to_i64().to_be_bytes();
+    if offset + amount_drops.len() > MAX_MEMO_LEN {
+        return exit("Bridge transfer failed", MEMO_TOO_LARGE);
+    }
+    memo[offset..offset + amount_drops.len()].copy_from_slice(&amount_drops);
+    offset += amount_drops.len();
+
+    // `.transfer()` has no memo-carrying variant in this stdlib, so the
+    // encoded memo is surfaced via trace rather than attached to the ledger
+    // transfer itself -- exactly what relayers and auditors are expected to
+    // read to reconstruct bridge intent (see `transfer_with_payload`, which
+    // hits the same stdlib limit).
+    let _ = trace_data("Bridge memo:", &memo[..offset], DataRepr::Hex);
+
+    // The on-chain effect is a normal transfer to the local bridge/custody
+    // account.
+    let tx_id = amount.transfer(&bridgeAccount);
+    if tx_id < 0 {
+        let _ = trace_num("AMOUNT Transfer Error Code:", tx_id as i64);
+        return exit("Bridge transfer failed", tx_id);
+    }
+
+    return exit("Bridge transfer successful", SUCCESS);
+}
@@ -4,31 +4,99 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
-use xrpl_wasm_std::core::params::function::{get_function_param, safe_get_function_param};
-use xrpl_wasm_std::core::types::account_id::AccountID;
-use xrpl_wasm_std::core::types::amount::Amount;
-use xrpl_wasm_std::host::trace::{trace_num, DataRepr};
+use xrpl_wasm_stdlib::wasm_export;
+use xrpl_wasm_stdlib::core::params::function::{get_function_param, safe_get_function_param};
+use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+use xrpl_wasm_stdlib::core::types::amount::Amount;
+use xrpl_wasm_stdlib::host::trace::{trace, trace_num, DataRepr};
 
 const SUCCESS: i32 = 0;
 const BAD_PARAM: i32 = -1;
+const CAP_EXCEEDED: i32 = -2;
+const DEST_NOT_ALLOWED: i32 = -3;
 
-#[unsafe(no_mangle)]
-pub extern "C" fn redirect() -> i32 {
+const ACCOUNT_ID_LEN: usize = 20;
 
-    // Approve
+fn exit(message: &str, error_code: i32) -> i32 {
+    let _ = trace(message);
+    let _ = trace_num("Error Code:", error_code as i64);
+    error_code
+}
+
+// A zero cap means "no cap configured" so the allowlist can be used on its
+// own without also having to pick an arbitrary maximum.
+fn check_cap(amount: &Amount, max_amount: &Amount) -> Option<i32> {
+    if max_amount.to_i64() == 0 {
+        return None;
+    }
+    if amount.to_i64() > max_amount.to_i64() {
+        let _ = trace_num("Amount exceeding cap:", amount.to_i64());
+        return Some(CAP_EXCEEDED);
+    }
+    None
+}
+
+// An empty allowlist means "allow all" so the cap can be enforced on its
+// own. Scans the whole list rather than short-circuiting on a match so the
+// check takes the same time regardless of where (or whether) the account
+// appears. Takes the destination as raw bytes (re-read from the same
+// function param as the typed `AccountID`, the way `transfer_any` reads
+// its amount param both raw and typed) rather than assuming `AccountID` exposes a byte
+// accessor that's never used elsewhere in this tree.
+fn check_allowlist(account_bytes: &[u8], allowlist: &[u8]) -> Option<i32> {
+    if allowlist.is_empty() {
+        return None;
+    }
+
+    let mut allowed = false;
+    let mut offset = 0;
+    while offset + ACCOUNT_ID_LEN <= allowlist.len() {
+        let entry = &allowlist[offset..offset + ACCOUNT_ID_LEN];
+        allowed |= entry == account_bytes;
+        offset += ACCOUNT_ID_LEN;
+    }
+
+    if allowed {
+        None
+    } else {
+        let _ = trace("Destination not in allowlist");
+        Some(DEST_NOT_ALLOWED)
+    }
+}
+
+#[wasm_export(
+    exit = exit,
+    instance(maxAmount: Amount, allowlist: &[u8])
+)]
+pub extern "C" fn redirect() -> i32 {
 
     // Get: Amount
     let amount = match get_function_param::<Amount>(0) {
         Ok(a) => a,
         Err(err) => {
             let _ = trace_num("`amount` Parameter Error Code:", err as i64);
-            return BAD_PARAM;
+            return exit("Redirect failed", BAD_PARAM);
         }
     };
 
     // Get: AccountID
     let account = safe_get_function_param::<AccountID>(1);
 
+    // Approve
+    if let Some(code) = check_cap(&amount, &maxAmount) {
+        return exit("Redirect failed", code);
+    }
+    let account_bytes = match get_function_param::<&[u8]>(1) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = trace_num("`account` Parameter Error Code:", err as i64);
+            return exit("Redirect failed", BAD_PARAM);
+        }
+    };
+    if let Some(code) = check_allowlist(account_bytes, allowlist) {
+        return exit("Redirect failed", code);
+    }
+
     // Transfer: from the "contract" to the "account"
     let tx_id = amount.transfer(&account);
     if tx_id < 0 {
@@ -38,3 +106,34 @@ pub extern "C" fn redirect() -> i32 {
 
     return SUCCESS;
 }
+
+#[wasm_export(
+    exit = exit,
+    instance(maxAmount: Amount, allowlist: &[u8])
+)]
+pub extern "C" fn guarded_transfer(
+    account: AccountID,
+    amount: Amount,
+) -> i32 {
+    if let Some(code) = check_cap(&amount, &maxAmount) {
+        return exit("Guarded transfer failed", code);
+    }
+    let account_bytes = match get_function_param::<&[u8]>(0) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = trace_num("`account` Parameter Error Code:", err as i64);
+            return exit("Guarded transfer failed", BAD_PARAM);
+        }
+    };
+    if let Some(code) = check_allowlist(account_bytes, allowlist) {
+        return exit("Guarded transfer failed", code);
+    }
+
+    let tx_id = amount.transfer(&account);
+    if tx_id < 0 {
+        let _ = trace_num("AMOUNT Transfer Error Code:", tx_id as i64);
+        return exit("Guarded transfer failed", tx_id);
+    }
+
+    return exit("Guarded transfer successful", SUCCESS);
+}
@@ -0,0 +1,66 @@
+#![allow(unused_imports)]
+#![cfg_attr(target_arch = "wasm32", no_std)]
+
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+use xrpl_wasm_stdlib::core::params::function::{get_function_param, safe_get_function_param};
+use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+use xrpl_wasm_stdlib::core::types::amount::Amount;
+use xrpl_wasm_stdlib::core::types::amount::mpt_amount::MPTAmount;
+use xrpl_wasm_stdlib::core::types::amount::token_amount::TokenAmount;
+use xrpl_wasm_stdlib::host::trace::{trace, trace_num, DataRepr};
+
+const SUCCESS: i32 = 0;
+const BAD_PARAM: i32 = -1;
+const UNRECOGNIZED_AMOUNT: i32 = -2;
+
+// Every serialized amount, of any variant, is at least this many bytes.
+const MIN_AMOUNT_LEN: usize = 8;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn transfer_any() -> i32 {
+
+    // Get: AccountID
+    let account = safe_get_function_param::<AccountID>(0);
+
+    // Peek: the raw amount blob, just to sanity-check its length.
+    let raw = match get_function_param::<&[u8]>(1) {
+        Ok(r) => r,
+        Err(err) => {
+            let _ = trace_num("`amount` Parameter Error Code:", err as i64);
+            return BAD_PARAM;
+        }
+    };
+
+    if raw.len() < MIN_AMOUNT_LEN {
+        let _ = trace_num("Amount blob too short:", raw.len() as i64);
+        return UNRECOGNIZED_AMOUNT;
+    }
+
+    // The leading bits of an STAmount overlap between variants (an
+    // issued-currency exponent can alias the MPT flag), so a single
+    // discriminator bit can't reliably tell them apart. Instead, attempt
+    // each typed parse in turn and go with whichever one actually decodes
+    // the blob; only reject it once none of them do.
+    let tx_id = if let Ok(amount) = get_function_param::<Amount>(1) {
+        let _ = trace("Detected variant: XRP");
+        amount.transfer(&account)
+    } else if let Ok(amount) = get_function_param::<MPTAmount>(1) {
+        let _ = trace("Detected variant: MPT");
+        amount.transfer(&account)
+    } else if let Ok(amount) = get_function_param::<TokenAmount>(1) {
+        let _ = trace("Detected variant: IOU");
+        amount.transfer(&account)
+    } else {
+        let _ = trace("Unrecognized amount encoding");
+        return UNRECOGNIZED_AMOUNT;
+    };
+
+    if tx_id < 0 {
+        let _ = trace_num("Transfer Error Code:", tx_id as i64);
+        return tx_id;
+    }
+
+    return SUCCESS;
+}
@@ -0,0 +1,99 @@
+#![allow(unused_imports)]
+#![cfg_attr(target_arch = "wasm32", no_std)]
+
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
+use xrpl_wasm_stdlib::wasm_export;
+use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+use xrpl_wasm_stdlib::core::types::amount::Amount;
+use xrpl_wasm_stdlib::host::trace::{trace, trace_num, DataRepr};
+
+const SUCCESS: i32 = 0;
+const BPS_OUT_OF_RANGE: i32 = -1;
+const SPLIT_MISMATCH: i32 = -2;
+
+const BPS_DENOMINATOR: i64 = 10000;
+
+fn exit(message: &str, error_code: i32) -> i32 {
+    let _ = trace(message);
+    let _ = trace_num("Error Code:", error_code as i64);
+    error_code
+}
+
+// `Amount` is only ever read via `to_i64()` elsewhere in this tree -- there
+// is no confirmed constructor for building a fresh `Amount` from raw drops.
+// So rather than computing a gross-to-net/fee split on-chain (which would
+// need exactly that), the caller supplies the already-split `net` and `fee`
+// legs as their own typed params, and this export only verifies the split
+// matches `fee_bps` before moving each leg with its own (already-
+// constructed) `Amount`.
+#[wasm_export(exit = exit)]
+pub extern "C" fn transfer_with_fee(
+    account: AccountID,
+    net: Amount,
+    fee: Amount,
+    treasury: AccountID,
+    fee_bps: u32,
+) -> i32 {
+    if fee_bps as i64 > BPS_DENOMINATOR {
+        let _ = trace_num("`fee_bps` out of range:", fee_bps as i64);
+        return exit("Fee transfer failed", BPS_OUT_OF_RANGE);
+    }
+
+    let net_drops = net.to_i64();
+    let fee_drops = fee.to_i64();
+    let gross_drops = net_drops.saturating_add(fee_drops);
+
+    let expected_fee_drops = gross_drops.saturating_mul(fee_bps as i64) / BPS_DENOMINATOR;
+    if fee_drops != expected_fee_drops {
+        let _ = trace_num("Expected fee drops:", expected_fee_drops);
+        let _ = trace_num("Supplied fee drops:", fee_drops);
+        return exit("Fee transfer failed", SPLIT_MISMATCH);
+    }
+
+    // A 100% fee (or a gross too small to leave a net) means there is no
+    // recipient leg to attempt; skip it rather than sending the host a
+    // zero-value transfer it may reject.
+    let net_tx_id = if net_drops == 0 { SUCCESS } else { net.transfer(&account) };
+    if net_tx_id < 0 {
+        // Recipient leg failed before the treasury was touched: reroute
+        // both legs to the treasury so nothing strands mid-split.
+        let _ = trace_num("Recipient leg Error Code:", net_tx_id as i64);
+        let fallback_net_tx_id = net.transfer(&treasury);
+        if fallback_net_tx_id < 0 {
+            let _ = trace_num("Treasury fallback Error Code:", fallback_net_tx_id as i64);
+            return exit("Fee transfer failed", fallback_net_tx_id);
+        }
+        if fee_drops != 0 {
+            let fallback_fee_tx_id = fee.transfer(&treasury);
+            if fallback_fee_tx_id < 0 {
+                let _ = trace_num("Treasury fallback Error Code:", fallback_fee_tx_id as i64);
+                return exit("Fee transfer failed", fallback_fee_tx_id);
+            }
+        }
+        return exit("Fee transfer rerouted to treasury", SUCCESS);
+    }
+
+    // A zero bps (or a gross amount too small to produce a non-zero fee)
+    // means there is no treasury leg to attempt; skip it rather than sending
+    // the host a zero-value transfer it may reject.
+    if fee_drops == 0 {
+        return exit("Fee transfer successful", SUCCESS);
+    }
+
+    let fee_tx_id = fee.transfer(&treasury);
+    if fee_tx_id < 0 {
+        // Treasury leg failed after the recipient was already paid: top the
+        // recipient up with the stranded fee rather than losing it.
+        let _ = trace_num("Treasury leg Error Code:", fee_tx_id as i64);
+        let fallback_tx_id = fee.transfer(&account);
+        if fallback_tx_id < 0 {
+            let _ = trace_num("Recipient fallback Error Code:", fallback_tx_id as i64);
+            return exit("Fee transfer failed", fallback_tx_id);
+        }
+        return exit("Fee transfer rerouted to recipient", SUCCESS);
+    }
+
+    return exit("Fee transfer successful", SUCCESS);
+}
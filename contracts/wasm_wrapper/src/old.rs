@@ -7,10 +7,32 @@ extern crate std;
 use xrpl_wasm_std::core::params::function::{get_function_param, safe_get_function_param};
 use xrpl_wasm_std::core::types::account_id::AccountID;
 use xrpl_wasm_std::core::types::amount::token_amount::TokenAmount;
-use xrpl_wasm_std::host::trace::{trace_num, DataRepr};
+use xrpl_wasm_std::host::trace::{trace_data, trace_num, DataRepr};
 
 const SUCCESS: i32 = 0;
 const BAD_PARAM: i32 = -1;
+const PAYLOAD_TOO_LARGE: i32 = -2;
+const NO_PAYLOAD: i32 = -3;
+
+// Payloads ride along as an opaque memo, so cap them well under a single
+// ledger object's size limit rather than trusting the caller.
+const MAX_PAYLOAD_LEN: usize = 1024;
+
+// Shared non-empty/size-cap rule for a payload blob, used identically by
+// `transfer_with_payload` and `read_transfer_payload` below. There is no
+// host primitive in this tree that carries a payload from one of these
+// calls to the other -- each validates whatever bytes its own caller
+// handed it.
+fn validate_payload(payload: &[u8]) -> Option<i32> {
+    if payload.is_empty() {
+        return Some(NO_PAYLOAD);
+    }
+    if payload.len() > MAX_PAYLOAD_LEN {
+        let _ = trace_num("Payload length:", payload.len() as i64);
+        return Some(PAYLOAD_TOO_LARGE);
+    }
+    None
+}
 
 #[unsafe(no_mangle)]
 pub extern "C" fn easymode() -> i32 {
@@ -36,3 +58,73 @@ pub extern "C" fn easymode() -> i32 {
 
     return SUCCESS;
 }
+
+#[unsafe(no_mangle)]
+pub extern "C" fn transfer_with_payload() -> i32 {
+
+    // Get: AccountID
+    let account = safe_get_function_param::<AccountID>(0);
+
+    // Get: TokenAmount
+    let amount = match get_function_param::<TokenAmount>(1) {
+        Ok(a) => a,
+        Err(err) => {
+            let _ = trace_num("`TokenAmount` Parameter Error Code:", err as i64);
+            return BAD_PARAM;
+        }
+    };
+
+    // Get: payload (length-prefixed, opaque to this contract)
+    let payload = match get_function_param::<&[u8]>(2) {
+        Ok(p) => p,
+        Err(err) => {
+            let _ = trace_num("`payload` Parameter Error Code:", err as i64);
+            return BAD_PARAM;
+        }
+    };
+
+    if let Some(code) = validate_payload(payload) {
+        return code;
+    }
+
+    let _ = trace_data("Transfer payload:", payload, DataRepr::Hex);
+
+    // Transfer: from the "contract" to the "account". This is a plain value
+    // transfer -- `.transfer()` has no memo-carrying variant in this tree,
+    // and nothing here invokes or passes data to a destination contract.
+    // The payload is only validated and traced for this call; it is not
+    // delivered anywhere.
+    let tx_id = amount.transfer(&account);
+    if tx_id < 0 {
+        let _ = trace_num("Transfer Error Code:", tx_id as i64);
+        return tx_id;
+    }
+
+    return SUCCESS;
+}
+
+// A standalone validator sharing `transfer_with_payload`'s size/emptiness
+// rules, exposed as its own call for whatever passes it a payload. This is
+// NOT wired to `transfer_with_payload` -- there is no host mechanism in
+// this tree for one call's bytes to reach another contract's invocation, so
+// a caller that wants a destination to see what a sender attached must
+// still pass those bytes explicitly as that destination's own function
+// param.
+#[unsafe(no_mangle)]
+pub extern "C" fn read_transfer_payload() -> i32 {
+    let payload = match get_function_param::<&[u8]>(0) {
+        Ok(p) => p,
+        Err(err) => {
+            let _ = trace_num("`payload` Parameter Error Code:", err as i64);
+            return BAD_PARAM;
+        }
+    };
+
+    if let Some(code) = validate_payload(payload) {
+        return code;
+    }
+
+    let _ = trace_data("Incoming transfer payload:", payload, DataRepr::Hex);
+
+    return SUCCESS;
+}